@@ -0,0 +1,107 @@
+use clap::{Parser, Subcommand};
+use datafusion::arrow::util::pretty::print_batches;
+use rustyshim::client::FlightClient;
+use std::io::Write;
+
+// Command line arguments
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The Flight server hostname
+    #[arg(long, default_value = "localhost")]
+    host: String,
+
+    /// The Flight server port
+    #[arg(long, default_value_t = 50051)]
+    port: u16,
+
+    /// Connect over TLS
+    #[arg(long, action)]
+    tls: bool,
+
+    /// The session username
+    #[arg(short, long)]
+    username: Option<String>,
+
+    /// The session password
+    #[arg(short, long)]
+    password: Option<String>,
+
+    /// Flag to read the session password from stdin
+    #[arg(long, action)]
+    password_stdin: bool,
+
+    /// Request an admin session
+    #[arg(long, action)]
+    admin: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the flights (tables) the server has registered
+    List,
+    /// Run a SQL query and print the results
+    Query { sql: String },
+    /// Invoke a named server action
+    Action { name: String },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    if args.password.is_some() && args.password_stdin {
+        panic!("You may not choose both the --password and --password-stdin argument");
+    }
+
+    let username = match args.username {
+        Some(provided) => provided,
+        None => {
+            let mut prompted = String::new();
+            print!("Username: ");
+            let _ = std::io::stdout().flush();
+            std::io::stdin()
+                .read_line(&mut prompted)
+                .expect("Invalid username");
+            prompted.trim().to_string()
+        }
+    };
+    let password = if args.password_stdin {
+        let mut prompted = String::new();
+        std::io::stdin()
+            .read_line(&mut prompted)
+            .expect("Invalid password via stdin");
+        prompted.trim().to_string()
+    } else {
+        match args.password {
+            Some(provided) => provided,
+            None => rpassword::prompt_password("Password: ")?,
+        }
+    };
+
+    let mut client = FlightClient::connect(&args.host, args.port, args.tls).await?;
+    client.handshake(&username, &password, args.admin).await?;
+
+    match args.command {
+        Command::List => {
+            for info in client.list_flights().await? {
+                if let Some(descriptor) = &info.flight_descriptor {
+                    println!("{}", descriptor.path.join("."));
+                }
+            }
+        }
+        Command::Query { sql } => {
+            let batches = client.execute(&sql).await?;
+            print_batches(&batches)?;
+        }
+        Command::Action { name } => {
+            let body = client.do_action(&name).await?;
+            println!("{}", String::from_utf8_lossy(&body));
+        }
+    }
+
+    Ok(())
+}
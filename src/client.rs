@@ -0,0 +1,202 @@
+use crate::flight::{pack_any, COMMAND_STATEMENT_QUERY_TYPE};
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::sql::CommandStatementQuery;
+use arrow_flight::{Action, Criteria, FlightDescriptor, FlightInfo, HandshakeRequest};
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::record_batch::RecordBatch;
+use futures::TryStreamExt;
+use prost::Message;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::Request;
+
+///////////////////////
+// FlightSQL client  //
+///////////////////////
+
+/// Errors produced anywhere along the client path: a failure establishing
+/// the transport, a server-side `Status`, or a failure decoding the Arrow
+/// IPC stream a `do_get` call returned.
+#[derive(Debug)]
+pub enum FlightClientError {
+    Transport(tonic::transport::Error),
+    Status(tonic::Status),
+    Arrow(ArrowError),
+}
+
+impl std::fmt::Display for FlightClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlightClientError::Transport(e) => write!(f, "{}", e),
+            FlightClientError::Status(e) => write!(f, "{}", e),
+            FlightClientError::Arrow(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for FlightClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FlightClientError::Transport(e) => Some(e),
+            FlightClientError::Status(e) => Some(e),
+            FlightClientError::Arrow(e) => Some(e),
+        }
+    }
+}
+
+impl From<tonic::transport::Error> for FlightClientError {
+    fn from(e: tonic::transport::Error) -> Self {
+        FlightClientError::Transport(e)
+    }
+}
+
+impl From<tonic::Status> for FlightClientError {
+    fn from(e: tonic::Status) -> Self {
+        FlightClientError::Status(e)
+    }
+}
+
+impl From<ArrowError> for FlightClientError {
+    fn from(e: ArrowError) -> Self {
+        FlightClientError::Arrow(e)
+    }
+}
+
+/// A thin, stateful wrapper around `FlightServiceClient<Channel>` that
+/// performs this server's three-message handshake, stashes the returned
+/// session token, and attaches it as `authorization` metadata on every
+/// subsequent call. Modeled on arrow-rs's mid-level `FlightClient`.
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+    token: Option<String>,
+}
+
+impl FlightClient {
+    /// Connect to a Flight server at `host:port`, optionally over TLS.
+    pub async fn connect(host: &str, port: u16, tls: bool) -> Result<Self, FlightClientError> {
+        let endpoint = Endpoint::from_shared(format!(
+            "{}://{host}:{port}",
+            if tls { "https" } else { "http" }
+        ))?;
+        let endpoint = if tls {
+            endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?
+        } else {
+            endpoint
+        };
+        let channel = endpoint.connect().await?;
+        Ok(FlightClient {
+            inner: FlightServiceClient::new(channel),
+            token: None,
+        })
+    }
+
+    /// Perform the username/password/admin-flag handshake and remember the
+    /// session token returned, for use by every later call.
+    pub async fn handshake(
+        &mut self,
+        username: &str,
+        password: &str,
+        request_admin: bool,
+    ) -> Result<(), FlightClientError> {
+        let messages = vec![
+            HandshakeRequest {
+                protocol_version: 0,
+                payload: bytes::Bytes::from(username.to_owned()),
+            },
+            HandshakeRequest {
+                protocol_version: 0,
+                payload: bytes::Bytes::from(password.to_owned()),
+            },
+            HandshakeRequest {
+                protocol_version: 0,
+                payload: bytes::Bytes::from(if request_admin { "1" } else { "0" }),
+            },
+        ];
+        let request = Request::new(futures::stream::iter(messages));
+        let mut responses = self.inner.handshake(request).await?.into_inner();
+        let response = responses
+            .message()
+            .await?
+            .ok_or_else(|| tonic::Status::internal("server closed handshake without a response"))?;
+        self.token = Some(String::from_utf8_lossy(&response.payload).into_owned());
+        Ok(())
+    }
+
+    fn authorize<T>(&self, message: T) -> Result<Request<T>, FlightClientError> {
+        let mut request = Request::new(message);
+        let token = self
+            .token
+            .as_ref()
+            .ok_or_else(|| tonic::Status::unauthenticated("handshake has not been performed"))?;
+        request.metadata_mut().insert(
+            "authorization",
+            token
+                .parse()
+                .map_err(|_| tonic::Status::internal("session token is not valid metadata"))?,
+        );
+        Ok(request)
+    }
+
+    /// List the flights (tables) the server currently has registered.
+    pub async fn list_flights(&mut self) -> Result<Vec<FlightInfo>, FlightClientError> {
+        let request = self.authorize(Criteria {
+            expression: vec![].into(),
+        })?;
+        let mut stream = self.inner.list_flights(request).await?.into_inner();
+        let mut flights = Vec::new();
+        while let Some(info) = stream.message().await? {
+            flights.push(info);
+        }
+        Ok(flights)
+    }
+
+    /// Run a SQL query end to end: `get_flight_info` to obtain a ticket,
+    /// then `do_get` to redeem it, collecting every returned `RecordBatch`.
+    pub async fn execute(&mut self, sql: &str) -> Result<Vec<RecordBatch>, FlightClientError> {
+        let cmd = pack_any(
+            COMMAND_STATEMENT_QUERY_TYPE,
+            &CommandStatementQuery {
+                query: sql.to_owned(),
+                transaction_id: None,
+            },
+        );
+        let descriptor = FlightDescriptor::new_cmd(cmd.encode_to_vec());
+        let request = self.authorize(descriptor)?;
+        let flight_info = self.inner.get_flight_info(request).await?.into_inner();
+        let endpoint = flight_info
+            .endpoint
+            .into_iter()
+            .next()
+            .ok_or_else(|| tonic::Status::internal("get_flight_info returned no endpoints"))?;
+        let ticket = endpoint
+            .ticket
+            .ok_or_else(|| tonic::Status::internal("endpoint carried no ticket"))?;
+
+        let request = self.authorize(ticket)?;
+        let stream = self
+            .inner
+            .do_get(request)
+            .await?
+            .into_inner()
+            .map_err(|status| status.into());
+        let batches: Vec<RecordBatch> = FlightRecordBatchStream::new_from_flight_data(stream)
+            .try_collect()
+            .await?;
+        Ok(batches)
+    }
+
+    /// Invoke a named server action and return the body of its first result.
+    pub async fn do_action(&mut self, name: &str) -> Result<Vec<u8>, FlightClientError> {
+        let action = Action {
+            r#type: name.to_owned(),
+            body: bytes::Bytes::new(),
+        };
+        let request = self.authorize(action)?;
+        let mut stream = self.inner.do_action(request).await?.into_inner();
+        let result = stream
+            .message()
+            .await?
+            .ok_or_else(|| tonic::Status::internal("action returned no result"))?;
+        Ok(result.body.to_vec())
+    }
+}
@@ -1,16 +1,29 @@
 use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::error::FlightError;
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery, CommandStatementIngest,
+    CommandStatementQuery, TicketStatementQuery,
+};
 use arrow_flight::{
     flight_service_server::FlightService, Action, ActionType, Criteria, Empty, FlightData,
     FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse, PutResult,
     SchemaResult, Ticket,
 };
-use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::array::{ArrayRef, BinaryArray, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::MemTable;
 use datafusion::error::DataFusionError;
 use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use prost::Message;
+use prost_types::Any;
 use rand::{distributions::Alphanumeric, Rng};
 use std::collections::HashMap;
 use std::pin::Pin;
@@ -18,6 +31,152 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tonic::{Request, Response, Status, Streaming};
+use x509_parser::parse_x509_certificate;
+
+///////////////////////////////////
+// FlightSQL command (de)coding  //
+///////////////////////////////////
+
+// `type_url`s for the FlightSQL protobuf messages this service understands,
+// carried inside a CMD-type `FlightDescriptor`'s `google.protobuf.Any`.
+pub(crate) const COMMAND_STATEMENT_QUERY_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandStatementQuery";
+pub(crate) const TICKET_STATEMENT_QUERY_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.TicketStatementQuery";
+const COMMAND_PREPARED_STATEMENT_QUERY_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandPreparedStatementQuery";
+const COMMAND_STATEMENT_INGEST_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandStatementIngest";
+
+// `Action.r#type` names for the FlightSQL prepared-statement actions; unlike
+// the CMD/Ticket payloads above, FlightSQL actions carry their message
+// directly in `Action.body` with no `Any` wrapper.
+const ACTION_CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const ACTION_CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+pub(crate) fn pack_any<T: Message>(type_url: &str, message: &T) -> Any {
+    Any {
+        type_url: type_url.to_owned(),
+        value: message.encode_to_vec(),
+    }
+}
+
+pub(crate) fn unpack_any<T: Message + Default>(any: &Any, type_url: &str) -> Option<T> {
+    if any.type_url == type_url {
+        T::decode(any.value.as_ref()).ok()
+    } else {
+        None
+    }
+}
+
+// `type_url`s for the FlightSQL catalog-metadata commands; each has a
+// result schema fixed by the spec and is answered by walking the
+// DataFusion catalog rather than running a query.
+const COMMAND_GET_CATALOGS_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandGetCatalogs";
+const COMMAND_GET_DB_SCHEMAS_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandGetDbSchemas";
+const COMMAND_GET_TABLES_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandGetTables";
+const COMMAND_GET_TABLE_TYPES_TYPE: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandGetTableTypes";
+
+/// A reduced SQL `LIKE` matcher: `%` matches any run of characters
+/// (including none); every other character, `_` included, is matched
+/// literally. That covers the prefix/suffix/substring patterns real
+/// FlightSQL drivers send without pulling in a regex dependency.
+fn like_match(pattern: &str, value: &str) -> bool {
+    if !pattern.contains('%') {
+        return pattern == value;
+    }
+    let mut rest = value;
+    for (i, segment) in pattern.split('%').enumerate() {
+        if segment.is_empty() {
+            continue;
+        }
+        match rest.find(segment) {
+            Some(idx) => rest = &rest[idx + segment.len()..],
+            None => return false,
+        }
+        if i == 0 && !pattern.starts_with('%') && !value.starts_with(segment) {
+            return false;
+        }
+    }
+    if !pattern.ends_with('%') {
+        if let Some(last) = pattern.split('%').next_back().filter(|s| !s.is_empty()) {
+            if !value.ends_with(last) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// A `FlightDescriptor`, decoded down to either a SQL string still needing
+/// `ctx.sql(..)`, a handle into `prepared_map` for an already-planned
+/// prepared statement, or an already-materialized catalog-metadata batch.
+enum ResolvedCommand {
+    Sql(String),
+    Prepared(String),
+    Metadata(RecordBatch),
+}
+
+/// The `$n` index a placeholder name refers to, for sorting positionally
+/// (`$2` before `$10`) rather than lexicographically. Returns `None` for a
+/// name that isn't `$` followed by an integer; callers must map that to
+/// `u32::MAX` (not rely on `Option`'s derived `Ord`, which sorts `None`
+/// *before* every `Some`) so those names sort last, after every real
+/// positional placeholder.
+fn placeholder_index(name: &str) -> Option<u32> {
+    name.strip_prefix('$')?.parse().ok()
+}
+
+/// Order placeholder names positionally (`$2` before `$10`) rather than
+/// lexicographically, sorting any name that isn't a `$n` placeholder last.
+fn sort_placeholders<T>(params: &mut [(String, T)]) {
+    params.sort_by_key(|(name, _)| (placeholder_index(name).unwrap_or(u32::MAX), name.clone()));
+}
+
+/// Derive a `Schema` describing this dataframe's `$n` placeholders, sorted
+/// by their positional index so a client that binds parameters in the
+/// order this schema lists them ends up binding `$1, $2, ..., $10, $11`
+/// correctly instead of the lexicographic `$1, $10, $11, ..., $2`; untyped
+/// placeholders default to `Utf8` since FlightSQL still requires *some*
+/// concrete type per field. Names that aren't `$n` placeholders sort last.
+fn parameter_schema_of(df: &DataFrame) -> Result<Schema, DataFusionError> {
+    let mut params: Vec<(String, DataType)> = df
+        .logical_plan()
+        .get_parameter_types()?
+        .into_iter()
+        .map(|(name, ty)| (name, ty.unwrap_or(DataType::Utf8)))
+        .collect();
+    sort_placeholders(&mut params);
+    Ok(Schema::new(
+        params
+            .into_iter()
+            .map(|(name, ty)| Field::new(name, ty, true))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[cfg(test)]
+mod placeholder_sort_tests {
+    use super::sort_placeholders;
+
+    #[test]
+    fn sorts_numerically_and_puts_non_numeric_names_last() {
+        let mut params: Vec<(String, ())> = vec![
+            ("$10".to_string(), ()),
+            ("$2".to_string(), ()),
+            ("$named".to_string(), ()),
+            ("$1".to_string(), ()),
+            ("$11".to_string(), ()),
+        ];
+        sort_placeholders(&mut params);
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["$1", "$2", "$10", "$11", "$named"]);
+    }
+}
 
 ///////////////////////////////////////////
 // DataFusion <-> Flight interop methods //
@@ -41,6 +200,26 @@ fn mderr_to_status(_e: tonic::metadata::errors::ToStrError) -> Status {
     Status::new(tonic::Code::Unknown, "error reading request header")
 }
 
+/// Pull the subject of the first client certificate presented over mTLS,
+/// if the connection is TLS-terminated with client certificate verification
+/// enabled (see `FlightTlsConfig`). Returns `None` for plaintext connections
+/// or TLS connections where the client didn't present a certificate.
+fn peer_cert_subject<T>(request: &Request<T>) -> Option<String> {
+    let certs = request.peer_certs()?;
+    let der = certs.first()?;
+    let (_, cert) = parse_x509_certificate(der.as_ref()).ok()?;
+    Some(cert.subject().to_string())
+}
+
+fn require_admin(auth: SessionType) -> Result<(), Status> {
+    if auth != SessionType::Admin {
+        return Err(Status::permission_denied(
+            "permission to perform admin action denied",
+        ));
+    }
+    Ok(())
+}
+
 // Convert this DFSchema to Bytes, which is
 // surprisingly verbose and requires picking some IpcWriteOptions
 fn schema_to_bytes(schema: &Schema) -> bytes::Bytes {
@@ -73,17 +252,27 @@ pub struct ClientSessionInfo {
     session_type: SessionType,
 }
 
-// TODO:
-// - implement timeout based on creation time
-// - store token to add per-session protection
 #[derive(Clone)]
 pub struct TicketInfo {
     start: Instant,
     dataframe: DataFrame,
+    owner_token: String,
+}
+
+// A prepared statement: the still-unexecuted logical plan `ctx.sql`
+// produced, plus the schema of the `$n` placeholders it expects to be
+// bound via `do_put` before it can be turned into a ticket.
+#[derive(Clone)]
+pub struct PreparedInfo {
+    start: Instant,
+    dataframe: DataFrame,
+    parameter_schema: Schema,
+    owner_token: String,
 }
 
 type SessionMap = Arc<RwLock<HashMap<String, ClientSessionInfo>>>;
 type TicketMap = Arc<RwLock<HashMap<String, TicketInfo>>>;
+type PreparedMap = Arc<RwLock<HashMap<String, PreparedInfo>>>;
 
 pub trait FusionFlightAdministrator {
     // Authentication and authorization
@@ -94,14 +283,56 @@ pub trait FusionFlightAdministrator {
         request_admin: bool,
     ) -> SessionType;
 
+    /// Map a verified mTLS client certificate subject to a `SessionType`,
+    /// for deployments that authenticate via client certificate rather than
+    /// (or in addition to) the username/password handshake. Administrators
+    /// that don't support certificate-based auth can rely on this default,
+    /// which rejects every subject.
+    fn authenticate_peer(&self, _subject: &str) -> SessionType {
+        SessionType::Unauthenticated
+    }
+
     // (Re)create datafusion SessionContext
     fn refresh_context(&self) -> Result<SessionContext, Box<dyn std::error::Error>>;
 }
 
+///////////////////////
+// Transport security //
+///////////////////////
+
+/// Certificate material for terminating TLS on the Flight server: a server
+/// `Identity` (certificate + private key PEM paths) and, if mutual TLS is
+/// required, a CA certificate PEM path used to verify client certificates.
+/// Unlike [`crate::scidb::TlsConfig`], which is handed to the C++ SciDB
+/// client, this is consumed by `tonic::transport::Server` itself.
+pub struct FlightTlsConfig {
+    pub cert_path: String,
+    pub key_path: String,
+    pub client_ca_cert_path: Option<String>,
+}
+
+impl FlightTlsConfig {
+    /// Load the configured PEM files and build the `tonic` server TLS
+    /// config. Setting `client_ca_cert_path` both enables and requires
+    /// client certificate authentication for every connection.
+    pub fn to_server_tls_config(&self) -> std::io::Result<tonic::transport::ServerTlsConfig> {
+        let cert = std::fs::read_to_string(&self.cert_path)?;
+        let key = std::fs::read_to_string(&self.key_path)?;
+        let identity = tonic::transport::Identity::from_pem(cert, key);
+        let mut tls = tonic::transport::ServerTlsConfig::new().identity(identity);
+        if let Some(ca_path) = &self.client_ca_cert_path {
+            let ca_cert = std::fs::read_to_string(ca_path)?;
+            tls = tls.client_ca_root(tonic::transport::Certificate::from_pem(ca_cert));
+        }
+        Ok(tls)
+    }
+}
+
 pub struct FusionFlightService {
     ctx: Arc<RwLock<SessionContext>>,
     token_map: SessionMap,
     ticket_map: TicketMap,
+    prepared_map: PreparedMap,
     flight_info: Arc<RwLock<Vec<Result<FlightInfo, Status>>>>,
     administrator: Box<dyn FusionFlightAdministrator + Send + Sync + 'static>,
 }
@@ -144,6 +375,7 @@ impl FusionFlightService {
             ctx: Arc::new(RwLock::new(ctx)),
             token_map: Arc::new(RwLock::new(HashMap::<String, ClientSessionInfo>::new())),
             ticket_map: Arc::new(RwLock::new(HashMap::<String, TicketInfo>::new())),
+            prepared_map: Arc::new(RwLock::new(HashMap::<String, PreparedInfo>::new())),
             flight_info: Arc::new(RwLock::new(collected_flight_info)),
             administrator: administrator,
         }
@@ -171,29 +403,50 @@ impl FusionFlightService {
         token
     }
 
-    pub async fn validate_headers(
+    pub async fn validate_headers<T>(&self, request: &Request<T>) -> Result<SessionType, Status> {
+        self.validate_headers_with_token(request)
+            .await
+            .map(|(_identity, session_type)| session_type)
+    }
+
+    /// Like `validate_headers`, but also returns an identity string for the
+    /// caller, so ticket/prepared-handle owners can be recorded and checked.
+    /// This is either the bearer session token from the `authorization`
+    /// header, or, when no such header is present, an identity derived from
+    /// the client certificate presented during an mTLS handshake.
+    pub async fn validate_headers_with_token<T>(
         &self,
-        headers: &tonic::metadata::MetadataMap,
-    ) -> Result<SessionType, Status> {
+        request: &Request<T>,
+    ) -> Result<(String, SessionType), Status> {
+        let headers = request.metadata();
         dbg!(headers);
-        let provided_token = headers
-            .get("authorization")
-            .ok_or(Status::unauthenticated("no session token provided"))?
-            .to_str()
-            .map_err(mderr_to_status)?;
-
-        let db = self.token_map.read().await;
-        let info = (*db)
-            .get(provided_token)
-            .ok_or(Status::unauthenticated("invalid session token"))?;
-        let token_age = info.start.elapsed();
-        if token_age > ITEM_EXPIRATION_AGE {
-            return Err(Status::unauthenticated("expired session token"));
+        if let Some(provided_token) = headers.get("authorization") {
+            let provided_token = provided_token.to_str().map_err(mderr_to_status)?;
+            let db = self.token_map.read().await;
+            let info = (*db)
+                .get(provided_token)
+                .ok_or(Status::unauthenticated("invalid session token"))?;
+            let token_age = info.start.elapsed();
+            if token_age > ITEM_EXPIRATION_AGE {
+                return Err(Status::unauthenticated("expired session token"));
+            }
+            return Ok((provided_token.to_owned(), info.session_type));
+        }
+
+        // No bearer token: fall back to the client certificate presented
+        // during an mTLS handshake, letting the administrator map a
+        // verified identity straight to a `SessionType` without a separate
+        // username/password exchange.
+        let subject = peer_cert_subject(request)
+            .ok_or(Status::unauthenticated("no session token provided"))?;
+        let session_type = self.administrator.authenticate_peer(&subject);
+        if session_type == SessionType::Unauthenticated {
+            return Err(Status::unauthenticated("client certificate not recognized"));
         }
-        Ok(info.session_type)
+        Ok((format!("cert:{subject}"), session_type))
     }
 
-    pub async fn create_ticket(&self, dataframe: DataFrame) -> String {
+    pub async fn create_ticket(&self, dataframe: DataFrame, owner_token: String) -> String {
         let ticket: String = rand::thread_rng()
             .sample_iter(&Alphanumeric)
             .take(32)
@@ -205,14 +458,396 @@ impl FusionFlightService {
             TicketInfo {
                 start: Instant::now(),
                 dataframe: dataframe,
+                owner_token: owner_token,
             },
         );
         ticket
     }
 
-    pub async fn get_ticket(&self, ticket: String) -> Option<TicketInfo> {
+    /// Atomically remove and return a ticket under a single write-lock
+    /// acquisition, so two concurrent `do_get` calls for the same ticket
+    /// can't both observe it as present (via a read lock) before either
+    /// one removes it — only one redeemer can ever see `Some`.
+    pub async fn take_ticket(&self, ticket: &str) -> Option<TicketInfo> {
         let mut tdb = self.ticket_map.write().await;
-        (*tdb).remove(&ticket)
+        (*tdb).remove(ticket)
+    }
+
+    /// Re-insert a ticket that `take_ticket` removed but that turned out not
+    /// to be redeemable by the caller (e.g. the wrong session), so it's
+    /// still available for its actual owner to redeem later.
+    async fn restore_ticket(&self, ticket: String, info: TicketInfo) {
+        let mut tdb = self.ticket_map.write().await;
+        (*tdb).insert(ticket, info);
+    }
+
+    /// Look up a prepared statement, returning its `DataFrame` only if
+    /// `token` matches the session that created it. Mirrors the ownership
+    /// check `do_get` applies to tickets.
+    pub async fn get_prepared(&self, handle: &str, token: &str) -> Result<DataFrame, Status> {
+        let pdb = self.prepared_map.read().await;
+        let info = (*pdb)
+            .get(handle)
+            .ok_or_else(|| Status::not_found("prepared statement not found"))?;
+        if info.owner_token != token {
+            return Err(Status::permission_denied(
+                "prepared statement was issued to a different session",
+            ));
+        }
+        Ok(info.dataframe.clone())
+    }
+
+    /// Decode a `FlightDescriptor`, supporting a CMD-type descriptor
+    /// carrying a `prost`-encoded FlightSQL command (`CommandStatementQuery`,
+    /// `CommandPreparedStatementQuery`, or one of the catalog-metadata
+    /// commands), and the legacy PATH-type descriptor this service used to
+    /// require, where the raw query is stuffed into `path[0]`.
+    /// `unescape_path` preserves `get_flight_info`'s historical habit of
+    /// undoing an escaped quote in the PATH case; `get_schema` never did
+    /// this, so it passes `false`.
+    pub async fn resolve_command(
+        &self,
+        fd: &FlightDescriptor,
+        unescape_path: bool,
+    ) -> Result<ResolvedCommand, Status> {
+        if fd.r#type == DescriptorType::Cmd as i32 {
+            let any = Any::decode(&fd.cmd[..])
+                .map_err(|e| Status::invalid_argument(format!("invalid FlightSQL command: {e}")))?;
+            if let Some(cmd) =
+                unpack_any::<CommandStatementQuery>(&any, COMMAND_STATEMENT_QUERY_TYPE)
+            {
+                return Ok(ResolvedCommand::Sql(cmd.query));
+            }
+            if let Some(cmd) = unpack_any::<CommandPreparedStatementQuery>(
+                &any,
+                COMMAND_PREPARED_STATEMENT_QUERY_TYPE,
+            ) {
+                let handle = String::from_utf8_lossy(&cmd.prepared_statement_handle).into_owned();
+                return Ok(ResolvedCommand::Prepared(handle));
+            }
+            if let Some(batch) = self.catalog_metadata_batch(&any).await? {
+                return Ok(ResolvedCommand::Metadata(batch));
+            }
+            Err(Status::invalid_argument("unsupported FlightSQL command"))
+        } else {
+            // Note: abusing a FlightDescriptor of type PATH for clients that
+            // don't speak FlightSQL; effectively treating it as a CMD
+            // descriptor without encoding the query in the mandated format.
+            let query =
+                fd.path.first().cloned().ok_or_else(|| {
+                    Status::invalid_argument("missing query in flight descriptor")
+                })?;
+            Ok(ResolvedCommand::Sql(if unescape_path {
+                query.replace("\\\'", "'")
+            } else {
+                query
+            }))
+        }
+    }
+
+    /// Turn a `ResolvedCommand` into the `DataFrame` that will eventually
+    /// produce the result set, running `ctx.sql` / looking up
+    /// `prepared_map` / wrapping an already-materialized batch as needed.
+    /// `token` is the redeeming session's identity, checked against a
+    /// prepared statement's owner before it is handed back.
+    async fn dataframe_for(
+        &self,
+        resolved: ResolvedCommand,
+        token: &str,
+    ) -> Result<DataFrame, Status> {
+        match resolved {
+            ResolvedCommand::Sql(query) => {
+                let rctx = self.ctx.read().await;
+                (*rctx).sql(&query).await.map_err(dferr_to_status)
+            }
+            ResolvedCommand::Prepared(handle) => self.get_prepared(&handle, token).await,
+            ResolvedCommand::Metadata(batch) => {
+                let rctx = self.ctx.read().await;
+                (*rctx).read_batch(batch).map_err(dferr_to_status)
+            }
+        }
+    }
+
+    /// Answer the FlightSQL catalog-metadata commands by walking
+    /// `ctx.catalog(...)`/`schema_provider.table_names()`, exactly like
+    /// `FusionFlightService::new` does to build the default flights.
+    /// Returns `Ok(None)` if `any` isn't one of the commands handled here.
+    async fn catalog_metadata_batch(&self, any: &Any) -> Result<Option<RecordBatch>, Status> {
+        let to_status = |e: datafusion::arrow::error::ArrowError| Status::internal(e.to_string());
+
+        if unpack_any::<CommandGetCatalogs>(any, COMMAND_GET_CATALOGS_TYPE).is_some() {
+            let rctx = self.ctx.read().await;
+            let names = (*rctx).catalog_names();
+            let batch = RecordBatch::try_from_iter([(
+                "catalog_name",
+                Arc::new(StringArray::from(names)) as ArrayRef,
+            )])
+            .map_err(to_status)?;
+            return Ok(Some(batch));
+        }
+
+        if let Some(cmd) = unpack_any::<CommandGetDbSchemas>(any, COMMAND_GET_DB_SCHEMAS_TYPE) {
+            let rctx = self.ctx.read().await;
+            let mut catalog_col = Vec::new();
+            let mut schema_col = Vec::new();
+            for catalog_name in (*rctx).catalog_names() {
+                if cmd.catalog.as_ref().is_some_and(|c| c != &catalog_name) {
+                    continue;
+                }
+                let catalog = (*rctx)
+                    .catalog(&catalog_name)
+                    .expect("name just came from catalog_names()");
+                for schema_name in catalog.schema_names() {
+                    if let Some(pattern) = &cmd.db_schema_filter_pattern {
+                        if !like_match(pattern, &schema_name) {
+                            continue;
+                        }
+                    }
+                    catalog_col.push(catalog_name.clone());
+                    schema_col.push(schema_name);
+                }
+            }
+            let batch = RecordBatch::try_from_iter([
+                (
+                    "catalog_name",
+                    Arc::new(StringArray::from(catalog_col)) as ArrayRef,
+                ),
+                (
+                    "db_schema_name",
+                    Arc::new(StringArray::from(schema_col)) as ArrayRef,
+                ),
+            ])
+            .map_err(to_status)?;
+            return Ok(Some(batch));
+        }
+
+        if let Some(cmd) = unpack_any::<CommandGetTables>(any, COMMAND_GET_TABLES_TYPE) {
+            let rctx = self.ctx.read().await;
+            let mut catalog_col = Vec::new();
+            let mut schema_col = Vec::new();
+            let mut table_col = Vec::new();
+            let mut type_col = Vec::new();
+            let mut schema_bytes_col: Vec<Option<Vec<u8>>> = Vec::new();
+            for catalog_name in (*rctx).catalog_names() {
+                if cmd.catalog.as_ref().is_some_and(|c| c != &catalog_name) {
+                    continue;
+                }
+                let catalog = (*rctx)
+                    .catalog(&catalog_name)
+                    .expect("name just came from catalog_names()");
+                for schema_name in catalog.schema_names() {
+                    if let Some(pattern) = &cmd.db_schema_filter_pattern {
+                        if !like_match(pattern, &schema_name) {
+                            continue;
+                        }
+                    }
+                    let schema_provider = catalog
+                        .schema(&schema_name)
+                        .expect("name just came from schema_names()");
+                    for table_name in schema_provider.table_names() {
+                        if let Some(pattern) = &cmd.table_name_filter_pattern {
+                            if !like_match(pattern, &table_name) {
+                                continue;
+                            }
+                        }
+                        let table = schema_provider
+                            .table(&table_name)
+                            .await
+                            .ok_or_else(|| Status::internal("table disappeared mid-listing"))?;
+                        catalog_col.push(catalog_name.clone());
+                        schema_col.push(schema_name.clone());
+                        table_col.push(table_name);
+                        type_col.push("TABLE".to_string());
+                        if cmd.include_schema {
+                            schema_bytes_col.push(Some(schema_to_bytes(&table.schema()).to_vec()));
+                        } else {
+                            schema_bytes_col.push(None);
+                        }
+                    }
+                }
+            }
+            let mut columns: Vec<(&str, ArrayRef)> = vec![
+                (
+                    "catalog_name",
+                    Arc::new(StringArray::from(catalog_col)) as ArrayRef,
+                ),
+                (
+                    "db_schema_name",
+                    Arc::new(StringArray::from(schema_col)) as ArrayRef,
+                ),
+                (
+                    "table_name",
+                    Arc::new(StringArray::from(table_col)) as ArrayRef,
+                ),
+                (
+                    "table_type",
+                    Arc::new(StringArray::from(type_col)) as ArrayRef,
+                ),
+            ];
+            if cmd.include_schema {
+                let schema_bytes: Vec<Option<&[u8]>> = schema_bytes_col
+                    .iter()
+                    .map(|bytes| bytes.as_deref())
+                    .collect();
+                columns.push((
+                    "table_schema",
+                    Arc::new(BinaryArray::from_iter(schema_bytes)) as ArrayRef,
+                ));
+            }
+            let batch = RecordBatch::try_from_iter(columns).map_err(to_status)?;
+            return Ok(Some(batch));
+        }
+
+        if unpack_any::<CommandGetTableTypes>(any, COMMAND_GET_TABLE_TYPES_TYPE).is_some() {
+            let batch = RecordBatch::try_from_iter([(
+                "table_type",
+                Arc::new(StringArray::from(vec!["TABLE".to_string()])) as ArrayRef,
+            )])
+            .map_err(to_status)?;
+            return Ok(Some(batch));
+        }
+
+        Ok(None)
+    }
+
+    /// Drain the rest of a `do_put` stream into a `Vec`, prepending the
+    /// first message `do_put` already read off to decode the descriptor.
+    /// Callers must decide whether the call is authorized before calling
+    /// this, since it buffers the remaining messages in full.
+    async fn collect_flight_data(
+        stream: &mut Streaming<FlightData>,
+        first: FlightData,
+    ) -> Result<Vec<FlightData>, Status> {
+        let mut flight_data = vec![first];
+        while let Some(fd) = stream.message().await? {
+            flight_data.push(fd);
+        }
+        Ok(flight_data)
+    }
+
+    /// Bind parameter values onto a prepared statement: `flight_data` holds
+    /// a single `RecordBatch` of one row, each column a positional `$n`
+    /// value, as delivered by a `do_put` whose descriptor is a
+    /// `CommandPreparedStatementQuery`.
+    async fn do_put_bind_params(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        flight_data: Vec<FlightData>,
+        token: &str,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        let handle = String::from_utf8_lossy(&cmd.prepared_statement_handle).into_owned();
+
+        let fd_stream = futures::stream::iter(flight_data.into_iter().map(Ok::<_, FlightError>));
+        let batches: Vec<_> =
+            arrow_flight::decode::FlightRecordBatchStream::new_from_flight_data(fd_stream)
+                .try_collect()
+                .await
+                .map_err(|e| Status::new(tonic::Code::Unknown, e.to_string()))?;
+        let batch = batches
+            .into_iter()
+            .next()
+            .ok_or_else(|| Status::invalid_argument("no parameter values provided"))?;
+        let params = batch
+            .columns()
+            .iter()
+            .map(|col| ScalarValue::try_from_array(col, 0))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(dferr_to_status)?;
+
+        let mut pdb = self.prepared_map.write().await;
+        let info = pdb
+            .get_mut(&handle)
+            .ok_or_else(|| Status::not_found("prepared statement not found"))?;
+        if info.owner_token != token {
+            return Err(Status::permission_denied(
+                "prepared statement was issued to a different session",
+            ));
+        }
+        if params.len() != info.parameter_schema.fields().len() {
+            return Err(Status::invalid_argument(format!(
+                "expected {} bound parameters, got {}",
+                info.parameter_schema.fields().len(),
+                params.len()
+            )));
+        }
+        info.dataframe = info
+            .dataframe
+            .clone()
+            .with_param_values(params)
+            .map_err(dferr_to_status)?;
+        drop(pdb);
+
+        let response = futures::stream::iter(Vec::<Result<PutResult, Status>>::new());
+        Ok(tonic::Response::new(Box::pin(response)))
+    }
+
+    /// Register or append `flight_data`'s batches as an in-memory table
+    /// named `table_name`, then refresh the cached `flight_info` so
+    /// `list_flights` immediately reflects it. Assumes the caller has
+    /// already checked the session is `SessionType::Admin`.
+    async fn do_put_ingest(
+        &self,
+        table_name: String,
+        flight_data: Vec<FlightData>,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        let fd_stream = futures::stream::iter(flight_data.into_iter().map(Ok::<_, FlightError>));
+        let batches: Vec<RecordBatch> =
+            arrow_flight::decode::FlightRecordBatchStream::new_from_flight_data(fd_stream)
+                .try_collect()
+                .await
+                .map_err(|e| Status::new(tonic::Code::Unknown, e.to_string()))?;
+        let schema = batches
+            .first()
+            .map(|batch| batch.schema())
+            .ok_or_else(|| Status::invalid_argument("no record batches provided"))?;
+        let row_count: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+
+        let rctx = self.ctx.read().await;
+        let mut all_batches = if rctx.table_exist(&table_name).unwrap_or(false) {
+            (*rctx)
+                .sql(&format!("SELECT * FROM {table_name}"))
+                .await
+                .map_err(dferr_to_status)?
+                .collect()
+                .await
+                .map_err(dferr_to_status)?
+        } else {
+            Vec::new()
+        };
+        all_batches.extend(batches);
+
+        let table = MemTable::try_new(schema.clone(), vec![all_batches])
+            .map_err(|e| Status::internal(e.to_string()))?;
+        (*rctx)
+            .register_table(table_name.as_str(), Arc::new(table))
+            .map_err(dferr_to_status)?;
+        drop(rctx);
+
+        let table_info = FlightInfo {
+            schema: schema_to_bytes(&schema),
+            flight_descriptor: Some(FlightDescriptor::new_path(vec![table_name.clone()])),
+            endpoint: vec![],
+            total_records: -1,
+            total_bytes: -1,
+        };
+        let mut fidb = self.flight_info.write().await;
+        (*fidb).retain(|r| match r {
+            Ok(info) => info
+                .flight_descriptor
+                .as_ref()
+                .and_then(|d| d.path.first())
+                .map(|name| name != &table_name)
+                .unwrap_or(true),
+            Err(_) => true,
+        });
+        (*fidb).push(Ok(table_info));
+        drop(fidb);
+
+        let put_result = PutResult {
+            app_metadata: bytes::Bytes::from(row_count.to_string()),
+        };
+        let response = futures::stream::iter(vec![Ok(put_result)]);
+        Ok(tonic::Response::new(Box::pin(response)))
     }
 }
 
@@ -290,7 +925,7 @@ impl FlightService for FusionFlightService {
         _request: Request<Criteria>,
     ) -> Result<Response<Self::ListFlightsStream>, Status> {
         // Authorize
-        self.validate_headers(_request.metadata()).await?;
+        self.validate_headers(&_request).await?;
 
         // Send response
         let flight_info = self.flight_info.read().await;
@@ -303,27 +938,20 @@ impl FlightService for FusionFlightService {
         _request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         // Authorize
-        self.validate_headers(_request.metadata()).await?;
+        let (token, _auth) = self.validate_headers_with_token(&_request).await?;
 
-        // Note: abusing a FlightDescriptor of type PATH
-        // and effectively treating it as a flight descriptor
-        // of type CMD; to adhere strictly to the experimental
-        // FlightSQL protocol the sql query should be encoded
-        // in a specific command format
         let fd = _request.into_inner();
-        let query = fd.path[0].clone().replace("\\\'", "'");
-        // Do enough DataFusion logic to get the schema of sql output
-        let rctx = self.ctx.read().await;
-        let df = (*rctx).sql(&query).await.map_err(dferr_to_status)?;
+        let resolved = self.resolve_command(&fd, true).await?;
+        let df = self.dataframe_for(resolved, &token).await?;
         let schema: Schema = df.schema().into();
 
-        // Store this in the TicketMap
-        let ticket = self.create_ticket(df).await;
+        // Store this in the TicketMap, bound to the issuing session
+        let handle = self.create_ticket(df, token).await;
+        let ticket_statement = TicketStatementQuery {
+            statement_handle: handle.into_bytes().into(),
+        };
+        let ticket = pack_any(TICKET_STATEMENT_QUERY_TYPE, &ticket_statement).encode_to_vec();
 
-        // Return a flight info with the ticket exactly equal to the
-        // query string; this is inconsistent with the Flight standard
-        // and should be replaced by an opaque ticket that can be used
-        // to retrieve the DataFrame df created above and execute it
         let fi = FlightInfo {
             schema: schema_to_bytes(&schema),
             flight_descriptor: Some(fd),
@@ -345,19 +973,11 @@ impl FlightService for FusionFlightService {
         _request: Request<FlightDescriptor>,
     ) -> Result<Response<SchemaResult>, Status> {
         // Authorize
-        self.validate_headers(_request.metadata()).await?;
+        let (token, _auth) = self.validate_headers_with_token(&_request).await?;
 
-        // Note: abusing a FlightDescriptor of type PATH
-        // and effectively treating it as a flight descriptor
-        // of type CMD; to adhere strictly to the experimental
-        // FlightSQL protocol the sql query should be encoded
-        // in a specific command format
         let fd = _request.into_inner();
-        let query = fd.path[0].clone();
-
-        // Do enough DataFusion logic to get the schema of sql output
-        let rctx = self.ctx.read().await;
-        let df = (*rctx).sql(&query).await.map_err(dferr_to_status)?;
+        let resolved = self.resolve_command(&fd, false).await?;
+        let df = self.dataframe_for(resolved, &token).await?;
         let schema: Schema = df.schema().into();
         let sr = SchemaResult {
             schema: schema_to_bytes(&schema),
@@ -371,15 +991,29 @@ impl FlightService for FusionFlightService {
         _request: Request<Ticket>,
     ) -> Result<Response<Self::DoGetStream>, Status> {
         // Authorize
-        self.validate_headers(_request.metadata()).await?;
+        let (token, _auth) = self.validate_headers_with_token(&_request).await?;
 
         // Process
-        let ticket = _request.into_inner().ticket.escape_ascii().to_string();
-        let df = self
-            .get_ticket(ticket)
+        let ticket_bytes = _request.into_inner().ticket;
+        let handle = Any::decode(&ticket_bytes[..])
+            .ok()
+            .and_then(|any| unpack_any::<TicketStatementQuery>(&any, TICKET_STATEMENT_QUERY_TYPE))
+            .map(|t| String::from_utf8_lossy(&t.statement_handle).into_owned())
+            .unwrap_or_else(|| ticket_bytes.escape_ascii().to_string());
+        let ticket_info = self
+            .take_ticket(&handle)
             .await
             .ok_or(Status::not_found("ticket not found"))?;
-        let dfstream = df
+        if ticket_info.owner_token != token {
+            self.restore_ticket(handle, ticket_info).await;
+            return Err(Status::permission_denied(
+                "ticket was issued to a different session",
+            ));
+        }
+        if ticket_info.start.elapsed() > ITEM_EXPIRATION_AGE {
+            return Err(Status::not_found("ticket expired"));
+        }
+        let dfstream = ticket_info
             .dataframe
             .execute_stream()
             .await
@@ -399,27 +1033,69 @@ impl FlightService for FusionFlightService {
         &self,
         _request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
-        Err(Status::unauthenticated(
-            "PUT not authorized for this database",
-        ))
+        // Authorize
+        let (token, auth) = self.validate_headers_with_token(&_request).await?;
+
+        // Decode the descriptor off the first message and decide whether
+        // this call requires admin *before* buffering the rest of the
+        // stream, so a non-admin session can't force us to hold an
+        // unbounded amount of data in memory only to reject it afterward.
+        let mut stream = _request.into_inner();
+        let first = stream
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("empty do_put stream"))?;
+        let descriptor = first
+            .flight_descriptor
+            .clone()
+            .ok_or_else(|| Status::invalid_argument("missing flight descriptor"))?;
+
+        if descriptor.r#type == DescriptorType::Cmd as i32 {
+            let any = Any::decode(&descriptor.cmd[..])
+                .map_err(|e| Status::invalid_argument(format!("invalid FlightSQL command: {e}")))?;
+            if let Some(cmd) = unpack_any::<CommandPreparedStatementQuery>(
+                &any,
+                COMMAND_PREPARED_STATEMENT_QUERY_TYPE,
+            ) {
+                let flight_data = Self::collect_flight_data(&mut stream, first).await?;
+                return self.do_put_bind_params(cmd, flight_data, &token).await;
+            }
+            if let Some(cmd) =
+                unpack_any::<CommandStatementIngest>(&any, COMMAND_STATEMENT_INGEST_TYPE)
+            {
+                require_admin(auth)?;
+                let flight_data = Self::collect_flight_data(&mut stream, first).await?;
+                return self.do_put_ingest(cmd.table, flight_data).await;
+            }
+            return Err(Status::unauthenticated(
+                "PUT not authorized for this database",
+            ));
+        }
+
+        // PATH-type descriptor: the pre-FlightSQL ingestion entrypoint,
+        // naming the target table as path[0].
+        require_admin(auth)?;
+        let table_name = descriptor
+            .path
+            .first()
+            .cloned()
+            .ok_or_else(|| Status::invalid_argument("missing table name"))?;
+        let flight_data = Self::collect_flight_data(&mut stream, first).await?;
+        self.do_put_ingest(table_name, flight_data).await
     }
     async fn do_action(
         &self,
         _request: Request<Action>,
     ) -> Result<Response<Self::DoActionStream>, Status> {
         // Authorize
-        let auth = self.validate_headers(_request.metadata()).await?;
-        if auth != SessionType::Admin {
-            return Err(Status::permission_denied(
-                "permission to perform admin action denied",
-            ));
-        }
+        let (token, auth) = self.validate_headers_with_token(&_request).await?;
 
         // Perform action
         let action = _request.into_inner();
         let actiontype = action.r#type;
         match actiontype.as_str() {
             "REFRESH_CONTEXT" => {
+                require_admin(auth)?;
                 let mut wctx = self.ctx.write().await;
                 let new_ctx = self
                     .administrator
@@ -433,12 +1109,16 @@ impl FlightService for FusionFlightService {
                 Ok(tonic::Response::new(Box::pin(response)))
             }
             "CLEAR_EXPIRED_ITEMS" => {
+                require_admin(auth)?;
                 let mut tokdb = self.token_map.write().await;
                 let mut tikdb = self.ticket_map.write().await;
+                let mut pdb = self.prepared_map.write().await;
                 let tokcount = tokdb.len();
                 let tikcount = tikdb.len();
+                let pcount = pdb.len();
                 (*tokdb).retain(|_, v| v.start.elapsed() < ITEM_EXPIRATION_AGE);
                 (*tikdb).retain(|_, v| v.start.elapsed() < ITEM_EXPIRATION_AGE);
+                (*pdb).retain(|_, v| v.start.elapsed() < ITEM_EXPIRATION_AGE);
                 let result = arrow_flight::Result {
                     body: bytes::Bytes::from("SUCCESS"),
                 };
@@ -450,7 +1130,80 @@ impl FlightService for FusionFlightService {
                 let tikprune = arrow_flight::Result {
                     body: bytes::Bytes::from(format!("REMOVED {tikdiff} EXPIRED TICKETS")),
                 };
-                let response = futures::stream::iter(vec![Ok(result), Ok(tokprune), Ok(tikprune)]);
+                let pdiff = pcount - pdb.len();
+                let pprune = arrow_flight::Result {
+                    body: bytes::Bytes::from(format!(
+                        "REMOVED {pdiff} EXPIRED PREPARED STATEMENTS"
+                    )),
+                };
+                let response =
+                    futures::stream::iter(vec![Ok(result), Ok(tokprune), Ok(tikprune), Ok(pprune)]);
+                Ok(tonic::Response::new(Box::pin(response)))
+            }
+            ACTION_CREATE_PREPARED_STATEMENT => {
+                let req = ActionCreatePreparedStatementRequest::decode(&action.body[..]).map_err(
+                    |e| {
+                        Status::invalid_argument(format!(
+                            "invalid CreatePreparedStatement request: {e}"
+                        ))
+                    },
+                )?;
+                let rctx = self.ctx.read().await;
+                let df = (*rctx).sql(&req.query).await.map_err(dferr_to_status)?;
+                drop(rctx);
+                let dataset_schema: Schema = df.schema().into();
+                let parameter_schema = parameter_schema_of(&df).map_err(dferr_to_status)?;
+
+                let handle: String = rand::thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(32)
+                    .map(char::from)
+                    .collect();
+                let mut pdb = self.prepared_map.write().await;
+                (*pdb).insert(
+                    handle.clone(),
+                    PreparedInfo {
+                        start: Instant::now(),
+                        dataframe: df,
+                        parameter_schema: parameter_schema.clone(),
+                        owner_token: token.clone(),
+                    },
+                );
+                drop(pdb);
+
+                let result = ActionCreatePreparedStatementResult {
+                    prepared_statement_handle: handle.into_bytes().into(),
+                    dataset_schema: schema_to_bytes(&dataset_schema).into(),
+                    parameter_schema: schema_to_bytes(&parameter_schema).into(),
+                };
+                let body = arrow_flight::Result {
+                    body: bytes::Bytes::from(result.encode_to_vec()),
+                };
+                let response = futures::stream::iter(vec![Ok(body)]);
+                Ok(tonic::Response::new(Box::pin(response)))
+            }
+            ACTION_CLOSE_PREPARED_STATEMENT => {
+                let req =
+                    ActionClosePreparedStatementRequest::decode(&action.body[..]).map_err(|e| {
+                        Status::invalid_argument(format!(
+                            "invalid ClosePreparedStatement request: {e}"
+                        ))
+                    })?;
+                let handle = String::from_utf8_lossy(&req.prepared_statement_handle).into_owned();
+                let mut pdb = self.prepared_map.write().await;
+                if let Some(info) = (*pdb).get(&handle) {
+                    if info.owner_token != token {
+                        return Err(Status::permission_denied(
+                            "prepared statement was issued to a different session",
+                        ));
+                    }
+                }
+                (*pdb).remove(&handle);
+                drop(pdb);
+                let result = arrow_flight::Result {
+                    body: bytes::Bytes::from("SUCCESS"),
+                };
+                let response = futures::stream::iter(vec![Ok(result)]);
                 Ok(tonic::Response::new(Box::pin(response)))
             }
             _ => Err(Status::invalid_argument("invalid action")),
@@ -462,7 +1215,7 @@ impl FlightService for FusionFlightService {
         _request: Request<Empty>,
     ) -> Result<Response<Self::ListActionsStream>, Status> {
         // Authorize
-        let auth = self.validate_headers(_request.metadata()).await?;
+        let auth = self.validate_headers(&_request).await?;
         if auth != SessionType::Admin {
             return Err(Status::permission_denied(
                 "permission to perform admin action denied",
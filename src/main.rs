@@ -1,7 +1,9 @@
 use arrow_flight::flight_service_server::FlightServiceServer;
 use clap::Parser;
 use datafusion::prelude::*;
-use rustyshim::flight::{FusionFlightAdministrator, FusionFlightService, SessionType};
+use rustyshim::flight::{
+    FlightTlsConfig, FusionFlightAdministrator, FusionFlightService, SessionType,
+};
 use rustyshim::scidb::SciDBConnection;
 use serde::{Deserialize, Serialize};
 use serde_yaml;
@@ -54,6 +56,20 @@ struct Args {
     /// The path to the YAML config file to read
     #[arg(short, long)]
     config: std::path::PathBuf,
+
+    /// PEM path for the Flight server's TLS certificate; enables TLS when
+    /// given together with --tls-key
+    #[arg(long)]
+    tls_cert: Option<std::path::PathBuf>,
+
+    /// PEM path for the Flight server's TLS private key
+    #[arg(long)]
+    tls_key: Option<std::path::PathBuf>,
+
+    /// PEM path for a CA certificate used to verify client certificates;
+    /// when given, client certificates are required (mutual TLS)
+    #[arg(long)]
+    tls_client_ca: Option<std::path::PathBuf>,
 }
 
 // Authenticator class //
@@ -178,6 +194,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let addr = "127.0.0.1:50051".parse()?;
     let service = FusionFlightService::new(ctx, Box::new(admin)).await;
     let svc = FlightServiceServer::new(service);
-    Server::builder().add_service(svc).serve(addr).await?;
+
+    let mut builder = Server::builder();
+    match (args.tls_cert, args.tls_key) {
+        (Some(cert_path), Some(key_path)) => {
+            let tls = FlightTlsConfig {
+                cert_path: cert_path.to_string_lossy().into_owned(),
+                key_path: key_path.to_string_lossy().into_owned(),
+                client_ca_cert_path: args.tls_client_ca.map(|p| p.to_string_lossy().into_owned()),
+            };
+            builder = builder.tls_config(tls.to_server_tls_config()?)?;
+        }
+        (None, None) => {
+            if args.tls_client_ca.is_some() {
+                panic!("You may not supply --tls-client-ca without --tls-cert and --tls-key");
+            }
+        }
+        _ => panic!("You must supply both --tls-cert and --tls-key, or neither"),
+    }
+
+    builder.add_service(svc).serve(addr).await?;
     Ok(())
 }
@@ -6,6 +6,8 @@ use datafusion::arrow::record_batch::RecordBatch;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::os::raw::c_void;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 const MAX_VARLEN: usize = 4096;
 
@@ -37,66 +39,90 @@ pub struct SciDBConnection {
     c_ptr: *mut c_void,
 }
 
+// SAFETY: this assumes the wrapped C++ connection object has no thread
+// affinity, so that moving a `SciDBConnection` to another thread (never
+// sharing it across two threads at once) is sound; `AsyncSciDBConnection`
+// relies on this to hand the connection off to a `spawn_blocking` worker
+// thread. That assumption is NOT verified against the scidbclient source
+// here: client.cpp/client.h, which this crate links against via build.rs,
+// are not part of this repository snapshot. Confirm against the vendored
+// client (or an upstream thread-safety guarantee) before relying on this
+// impl against a new scidbclient version.
+unsafe impl Send for SciDBConnection {}
+
+/// Errors produced anywhere along the SciDB binding: a SciDB server error
+/// (numeric status plus explanation string), a failure decoding the Arrow
+/// IPC stream written by `aio_save`, an I/O failure touching the buffer
+/// file (or a string argument containing a NUL byte), or a failed attempt
+/// to open a connection.
+/// Unlike the flat struct this replaces, each variant keeps its underlying
+/// cause reachable through `source()` for `?`-propagation into
+/// `anyhow`/`thiserror` call sites.
 #[derive(Debug)]
-pub enum SciDBError {
-    ConnectionError(i32),
-    QueryError { code: i32, explanation: String },
-    NulError(std::ffi::NulError),
-    IoError(std::io::Error),
-    ArrowError(ArrowError),
+pub enum QueryError {
+    Scidb { code: i32, explanation: String },
+    Arrow(ArrowError),
+    Io(std::io::Error),
+    NotConnected,
 }
 
-impl From<std::ffi::NulError> for SciDBError {
-    fn from(e: std::ffi::NulError) -> SciDBError {
-        SciDBError::NulError(e)
+impl QueryError {
+    /// The SciDB numeric status code, if this error came from the server.
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            QueryError::Scidb { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::ffi::NulError> for QueryError {
+    fn from(e: std::ffi::NulError) -> QueryError {
+        QueryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e))
     }
 }
 
-impl From<std::io::Error> for SciDBError {
+impl From<std::io::Error> for QueryError {
     fn from(e: std::io::Error) -> Self {
-        SciDBError::IoError(e)
+        QueryError::Io(e)
     }
 }
 
-impl From<ArrowError> for SciDBError {
+impl From<ArrowError> for QueryError {
     fn from(e: ArrowError) -> Self {
-        SciDBError::ArrowError(e)
+        QueryError::Arrow(e)
     }
 }
 
-impl std::fmt::Display for SciDBError {
+impl std::fmt::Display for QueryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            SciDBError::ConnectionError(code) => {
-                write!(f, "error code {} encountered during SciDB connection", code)
-            }
-            SciDBError::QueryError { code, explanation } => write!(
+            QueryError::Scidb { code, explanation } => write!(
                 f,
                 "error code {} encountered during SciDB query; message: {}",
                 code, explanation
             ),
-            SciDBError::NulError(e) => write!(f, "{}", e.to_string()),
-            SciDBError::IoError(e) => write!(f, "{}", e.to_string()),
-            SciDBError::ArrowError(e) => write!(f, "{}", e.to_string()),
+            QueryError::Arrow(e) => write!(f, "{}", e),
+            QueryError::Io(e) => write!(f, "{}", e),
+            QueryError::NotConnected => write!(f, "SciDB connection is not open"),
         }
     }
 }
 
-impl std::error::Error for SciDBError {
+impl std::error::Error for QueryError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self {
-            SciDBError::NulError(e) => Some(e),
-            SciDBError::IoError(e) => Some(e),
-            SciDBError::ArrowError(e) => Some(e),
+            QueryError::Arrow(e) => Some(e),
+            QueryError::Io(e) => Some(e),
             _ => None,
         }
     }
 }
 
-impl From<SciDBError> for tonic::Status {
-    fn from(e: SciDBError) -> tonic::Status {
+impl From<QueryError> for tonic::Status {
+    fn from(e: QueryError) -> tonic::Status {
         match e {
-            SciDBError::ConnectionError(_) => {
+            QueryError::NotConnected => {
                 tonic::Status::unauthenticated("SciDB authentication failed")
             }
             _ => tonic::Status::unknown(e.to_string()),
@@ -112,7 +138,8 @@ impl SciDBConnection {
         username: &str,
         password: &str,
         scidbport: i32,
-    ) -> Result<SciDBConnection, SciDBError> {
+        admin: bool,
+    ) -> Result<SciDBConnection, QueryError> {
         let mut status: i32 = 0;
         let sp = &mut status as *mut i32;
         let chostname = CString::new(hostname)?;
@@ -124,16 +151,75 @@ impl SciDBConnection {
                 scidbport,
                 cusername.as_ptr(),
                 cpassword.as_ptr(),
-                0,
+                admin as i32,
                 sp,
             )
         };
         if status == 0 && c_conn != 0 as *mut c_void {
             return Ok(SciDBConnection { c_ptr: c_conn });
         } else {
-            return Err(SciDBError::ConnectionError(status));
+            return Err(QueryError::NotConnected);
         }
     }
+
+    /// Connect over TLS, mirroring `new` but handing the CA certificate,
+    /// client certificate/key pair, and an optional key passphrase down to
+    /// `c_scidb_connect_tls` so the handshake can be performed against a
+    /// SciDB instance that does not accept plaintext connections.
+    pub fn new_tls(
+        hostname: &str,
+        username: &str,
+        password: &str,
+        scidbport: i32,
+        admin: bool,
+        tls: &TlsConfig,
+    ) -> Result<SciDBConnection, QueryError> {
+        let mut status: i32 = 0;
+        let sp = &mut status as *mut i32;
+        let chostname = CString::new(hostname)?;
+        let cusername = CString::new(username)?;
+        let cpassword = CString::new(password)?;
+        let cca_cert = CString::new(&tls.ca_cert_path[..])?;
+        let cclient_cert = CString::new(&tls.client_cert_path[..])?;
+        let cclient_key = CString::new(&tls.client_key_path[..])?;
+        let ckey_pass = tls
+            .key_passphrase
+            .as_deref()
+            .map(CString::new)
+            .transpose()?;
+        let ckey_pass_ptr = ckey_pass.as_ref().map_or(std::ptr::null(), |p| p.as_ptr());
+        let c_conn = unsafe {
+            c_scidb_connect_tls(
+                chostname.as_ptr(),
+                scidbport,
+                cusername.as_ptr(),
+                cpassword.as_ptr(),
+                admin as i32,
+                cca_cert.as_ptr(),
+                cclient_cert.as_ptr(),
+                cclient_key.as_ptr(),
+                ckey_pass_ptr,
+                sp,
+            )
+        };
+        if status == 0 && c_conn != 0 as *mut c_void {
+            return Ok(SciDBConnection { c_ptr: c_conn });
+        } else {
+            return Err(QueryError::NotConnected);
+        }
+    }
+}
+
+/// Certificate material for [`SciDBConnection::new_tls`]: a CA certificate
+/// used to verify the server, a client certificate/private key pair for
+/// mutual TLS, and an optional passphrase protecting the private key.
+/// Paths are handed down to the C++ client, which loads the PEM files
+/// itself rather than having Rust parse them.
+pub struct TlsConfig {
+    pub ca_cert_path: String,
+    pub client_cert_path: String,
+    pub client_key_path: String,
+    pub key_passphrase: Option<String>,
 }
 
 impl Drop for SciDBConnection {
@@ -153,8 +239,14 @@ pub struct QueryResult {
     ptr: *mut c_void, // content is non-null pointer to C++ object to be deleted at Drop
 }
 
+// SAFETY: see the `Send` impl on `SciDBConnection` above, including the
+// caveat that the underlying assumption is unverified in this snapshot.
+// The same reasoning applies here so a `QueryResult` can be shared with a
+// `spawn_blocking` worker thread via `AsyncSciDBConnection`.
+unsafe impl Send for QueryResult {}
+
 impl QueryResult {
-    fn new() -> QueryResult {
+    pub fn new() -> QueryResult {
         let qr = unsafe { c_init_query_result() };
         assert!(qr != 0 as *mut c_void); // not attempting to recover from memory allocation errors
         QueryResult { ptr: qr }
@@ -179,7 +271,7 @@ impl Drop for QueryResult {
 
 impl SciDBConnection {
     // Preparation step
-    pub fn prepare_query(&mut self, query: &str, result: &QueryResult) -> Option<SciDBError> {
+    pub fn prepare_query(&mut self, query: &str, result: &QueryResult) -> Option<QueryError> {
         let cquery = CString::new(query).ok()?;
         let mut errbuf = vec![0; MAX_VARLEN];
         let errbufptr = errbuf.as_mut_ptr() as *mut i8;
@@ -190,7 +282,7 @@ impl SciDBConnection {
         if code == 0 && error.is_empty() {
             None
         } else {
-            Some(SciDBError::QueryError {
+            Some(QueryError::Scidb {
                 code: code,
                 explanation: error,
             })
@@ -202,7 +294,7 @@ impl SciDBConnection {
         &mut self,
         query: &str,
         result: &QueryResult,
-    ) -> Option<SciDBError> {
+    ) -> Option<QueryError> {
         let cquery = CString::new(query).ok()?;
         let mut errbuf = vec![0; MAX_VARLEN];
         let errbufptr = errbuf.as_mut_ptr() as *mut i8;
@@ -214,7 +306,7 @@ impl SciDBConnection {
         if code == 0 && error.is_empty() {
             None
         } else {
-            Some(SciDBError::QueryError {
+            Some(QueryError::Scidb {
                 code: code,
                 explanation: error,
             })
@@ -222,7 +314,7 @@ impl SciDBConnection {
     }
 
     // Completion
-    pub fn complete_query(&mut self, result: &QueryResult) -> Option<SciDBError> {
+    pub fn complete_query(&mut self, result: &QueryResult) -> Option<QueryError> {
         let mut errbuf = vec![0; MAX_VARLEN];
         let errbufptr = errbuf.as_mut_ptr() as *mut i8;
         let code = unsafe { c_complete_query(self.c_ptr.clone(), result.ptr, errbufptr) };
@@ -231,7 +323,7 @@ impl SciDBConnection {
         if code == 0 && error.is_empty() {
             None
         } else {
-            Some(SciDBError::QueryError {
+            Some(QueryError::Scidb {
                 code: code,
                 explanation: error,
             })
@@ -239,7 +331,7 @@ impl SciDBConnection {
     }
 
     // All-in-one method
-    pub fn execute_query(&mut self, query: &str) -> Result<QueryID, SciDBError> {
+    pub fn execute_query(&mut self, query: &str) -> Result<QueryID, QueryError> {
         let mut qr = QueryResult::new();
 
         // Prep
@@ -264,6 +356,73 @@ impl SciDBConnection {
     }
 }
 
+/////////////////
+// QueryStats  //
+/////////////////
+
+/* Optional per-query instrumentation: how long each of the
+ * prepare/execute/complete phases took, and how much data came back.
+ * `execute_query_timed`/`execute_aio_query_timed` return these alongside
+ * the normal result so a benchmarking harness can feed them into an
+ * `hdrhistogram::Histogram` (see `SciDBPool::record` below) and report
+ * latency percentiles across many queries.
+ */
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryStats {
+    pub prepare: Duration,
+    pub execute: Duration,
+    pub complete: Duration,
+    /// Time spent reading the result back out of the Arrow temp file
+    /// (only populated by the `*_timed` `AioQuery` helpers).
+    pub read: Duration,
+    pub batch_count: usize,
+    pub total_rows: usize,
+    pub total_bytes: usize,
+}
+
+impl QueryStats {
+    /// Sum of the prepare/execute/complete/read phase durations.
+    pub fn total_duration(&self) -> Duration {
+        self.prepare + self.execute + self.complete + self.read
+    }
+}
+
+impl SciDBConnection {
+    /// Like `execute_query`, but times the prepare/execute/complete
+    /// phases and returns them as `QueryStats` alongside the `QueryID`.
+    pub fn execute_query_timed(
+        &mut self,
+        query: &str,
+    ) -> Result<(QueryID, QueryStats), QueryError> {
+        let mut qr = QueryResult::new();
+        let mut stats = QueryStats::default();
+
+        let start = Instant::now();
+        let error = self.prepare_query(&query, &mut qr);
+        stats.prepare = start.elapsed();
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let start = Instant::now();
+        let error = self.execute_prepared_query(&query, &mut qr);
+        stats.execute = start.elapsed();
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let start = Instant::now();
+        let error = self.complete_query(&mut qr);
+        stats.complete = start.elapsed();
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok((qr.id(), stats))
+    }
+}
+
 ////////////////
 // AioQuery //
 ////////////////
@@ -278,7 +437,7 @@ pub struct AioQuery {
 }
 
 impl AioQuery {
-    pub fn new() -> Result<AioQuery, SciDBError> {
+    pub fn new() -> Result<AioQuery, QueryError> {
         let buffer = tempfile::NamedTempFile::new()?;
         let path = buffer.into_temp_path(); // consumes and closes buffer();
         return Ok(AioQuery {
@@ -300,39 +459,78 @@ impl AioQuery {
         Some(aio_query)
     }
 
-    pub fn to_batches(self) -> Result<Vec<RecordBatch>, SciDBError> {
-        self.into()
-    }
-}
-
-impl Into<Result<Vec<RecordBatch>, SciDBError>> for AioQuery {
-    fn into(self) -> Result<Vec<RecordBatch>, SciDBError> {
-        let pathstr = self.buffer_path.to_str().ok_or(SciDBError::QueryError {
-            code: SHIM_IO_ERROR,
-            explanation: "cannot convert path to string".to_owned(),
+    /// Open the buffer file and return a lazy iterator over its
+    /// `RecordBatch`es, so a multi-gigabyte SciDB result can be processed
+    /// batch-by-batch instead of being collected into memory all at once.
+    /// The temp file is kept alive (and deleted) by the returned stream.
+    pub fn batches(self) -> Result<BatchStream, QueryError> {
+        let pathstr = self.buffer_path.to_str().ok_or_else(|| {
+            QueryError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "cannot convert path to string",
+            ))
         })?;
         let file = std::fs::File::open(&pathstr)?;
-        let ipc_reader = ipc::reader::StreamReader::try_new(file, None)?;
-        let batches: Vec<_> = ipc_reader.collect();
-        let mut filtered_batches: Vec<RecordBatch> = vec![];
-        for batch in batches {
-            let goodbatch = batch?;
-            filtered_batches.push(goodbatch);
+        let reader = ipc::reader::StreamReader::try_new(file, None)?;
+        Ok(BatchStream {
+            reader: reader,
+            _buffer_path: self.buffer_path,
+        })
+    }
+
+    /// Convenience wrapper around `batches` that eagerly collects every
+    /// batch into a `Vec`.
+    pub fn to_batches(self) -> Result<Vec<RecordBatch>, QueryError> {
+        self.batches()?.collect()
+    }
+
+    /// Like `to_batches`, but fills in the `batch_count`/`total_rows`/
+    /// `total_bytes` fields of `stats` as batches are read from the temp
+    /// file.
+    pub fn to_batches_timed(self, stats: &mut QueryStats) -> Result<Vec<RecordBatch>, QueryError> {
+        let start = Instant::now();
+        let mut batches = vec![];
+        for batch in self.batches()? {
+            let batch = batch?;
+            stats.total_rows += batch.num_rows();
+            stats.total_bytes += batch.get_array_memory_size();
+            batches.push(batch);
         }
+        stats.batch_count = batches.len();
+        stats.read = start.elapsed();
+        Ok(batches)
+    }
+}
+
+/// A lazy iterator over the `RecordBatch`es written by an `aio_save` query,
+/// reading from the Arrow IPC stream as batches are pulled. The backing
+/// temp file is held for the iterator's lifetime and removed on drop.
+pub struct BatchStream {
+    reader: ipc::reader::StreamReader<std::fs::File>,
+    _buffer_path: tempfile::TempPath,
+}
+
+impl Iterator for BatchStream {
+    type Item = Result<RecordBatch, QueryError>;
 
-        Ok(filtered_batches)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader
+            .next()
+            .map(|batch| batch.map_err(QueryError::from))
     }
 }
 
 impl SciDBConnection {
-    pub fn execute_aio_query(&mut self, query: &str) -> Result<AioQuery, SciDBError> {
+    pub fn execute_aio_query(&mut self, query: &str) -> Result<AioQuery, QueryError> {
         // Create AioQuery buffer and get path
         let mut aio = AioQuery::new()?;
 
         // Wrap AFL to save it to the buffer file in arrow format
-        let aio_query = aio.query_str(query).ok_or(SciDBError::QueryError {
-            code: SHIM_IO_ERROR,
-            explanation: "cannot convert path to string".to_owned(),
+        let aio_query = aio.query_str(query).ok_or_else(|| {
+            QueryError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "cannot convert path to string",
+            ))
         })?;
 
         // Execute the SciDB query, saving data to the buffer file
@@ -341,4 +539,380 @@ impl SciDBConnection {
         // Return QueryID result
         Ok(aio)
     }
+
+    /// Like `execute_aio_query`, but times the prepare/execute/complete
+    /// phases of the underlying `aio_save` query and returns them as
+    /// `QueryStats` alongside the `AioQuery`. Call
+    /// `AioQuery::to_batches_timed` on the result to also fill in the
+    /// `read`/`batch_count`/`total_rows`/`total_bytes` fields.
+    pub fn execute_aio_query_timed(
+        &mut self,
+        query: &str,
+    ) -> Result<(AioQuery, QueryStats), QueryError> {
+        let mut aio = AioQuery::new()?;
+
+        let aio_query = aio.query_str(query).ok_or_else(|| {
+            QueryError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "cannot convert path to string",
+            ))
+        })?;
+
+        let (qid, stats) = self.execute_query_timed(&aio_query)?;
+        aio.qid = qid;
+
+        Ok((aio, stats))
+    }
+}
+
+/////////////////
+// PreparedQuery //
+/////////////////
+
+/* Builds a correctly-escaped AFL/AQL query string from a template with
+ * positional `?` placeholders, so callers binding values from user input
+ * don't need to hand-splice strings the way `AioQuery::query_str` does
+ * today.
+ */
+
+#[derive(Clone, Debug)]
+pub enum BindValue {
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl BindValue {
+    fn to_literal(&self) -> String {
+        match self {
+            BindValue::Int(i) => i.to_string(),
+            BindValue::Float(f) => f.to_string(),
+            BindValue::Str(s) => format!("'{}'", s.replace('\'', "''")),
+        }
+    }
+}
+
+fn bind_error(message: &str) -> QueryError {
+    QueryError::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        message.to_owned(),
+    ))
+}
+
+pub struct PreparedQuery {
+    template: String,
+}
+
+impl PreparedQuery {
+    pub fn new(template: &str) -> PreparedQuery {
+        PreparedQuery {
+            template: template.to_owned(),
+        }
+    }
+
+    /// Substitute the template's positional `?` placeholders with the
+    /// given values, each escaped/quoted according to its type, producing
+    /// a query string ready for `prepare_query`. The same `PreparedQuery`
+    /// can be bound repeatedly with different values to run the template
+    /// against a connection more than once.
+    pub fn bind(&self, values: &[BindValue]) -> Result<String, QueryError> {
+        let mut result = String::with_capacity(self.template.len());
+        let mut values = values.iter();
+        for ch in self.template.chars() {
+            if ch == '?' {
+                let value = values.next().ok_or_else(|| {
+                    bind_error("not enough bound values for prepared query template")
+                })?;
+                result.push_str(&value.to_literal());
+            } else {
+                result.push(ch);
+            }
+        }
+        if values.next().is_some() {
+            return Err(bind_error(
+                "too many bound values for prepared query template",
+            ));
+        }
+        Ok(result)
+    }
+}
+
+//////////////
+// SciDBPool //
+//////////////
+
+/* An r2d2-style pool of SciDBConnections, so a workload can run several
+ * queries concurrently (or reuse a connection across queries) instead of
+ * paying the connect cost and serializing on a single SciDBConnection.
+ * Idle connections are validated with a cheap `list()` query before being
+ * handed back out; a connection that fails validation is dropped, and a
+ * checkout opens a fresh connection once no idle one is left. Every
+ * checked-out connection returns to the pool when its guard is dropped.
+ */
+
+struct PoolCreds {
+    hostname: String,
+    username: String,
+    password: String,
+    port: i32,
+    admin: bool,
+}
+
+pub struct SciDBPool {
+    creds: PoolCreds,
+    idle: Arc<Mutex<Vec<SciDBConnection>>>,
+    // Aggregate latency histogram (microseconds) across every query this
+    // pool's callers choose to report via `record`.
+    histogram: Arc<Mutex<hdrhistogram::Histogram<u64>>>,
+}
+
+pub struct SciDBPoolBuilder {
+    size: u32,
+    admin: bool,
+}
+
+impl SciDBPoolBuilder {
+    /// Number of connections to pre-establish when the pool is built.
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Whether pooled connections should authenticate as admin.
+    pub fn admin(mut self, admin: bool) -> Self {
+        self.admin = admin;
+        self
+    }
+
+    pub fn build(
+        self,
+        hostname: &str,
+        port: i32,
+        username: &str,
+        password: &str,
+    ) -> Result<SciDBPool, QueryError> {
+        let creds = PoolCreds {
+            hostname: hostname.to_owned(),
+            username: username.to_owned(),
+            password: password.to_owned(),
+            port: port,
+            admin: self.admin,
+        };
+        let mut idle = Vec::with_capacity(self.size as usize);
+        for _ in 0..self.size {
+            idle.push(SciDBConnection::new(
+                &creds.hostname,
+                &creds.username,
+                &creds.password,
+                creds.port,
+                creds.admin,
+            )?);
+        }
+        // 1 microsecond lowest value, 60 s (60,000,000 us) starting ceiling,
+        // 3 significant figures, but with auto-resize enabled: a fixed upper
+        // bound would silently drop
+        // exactly the slow-query outliers this histogram exists to surface.
+        let mut histogram = hdrhistogram::Histogram::new_with_bounds(1, 60_000_000, 3)
+            .expect("invalid histogram bounds");
+        histogram.auto(true);
+        Ok(SciDBPool {
+            creds: creds,
+            idle: Arc::new(Mutex::new(idle)),
+            histogram: Arc::new(Mutex::new(histogram)),
+        })
+    }
+}
+
+impl SciDBPool {
+    pub fn builder() -> SciDBPoolBuilder {
+        SciDBPoolBuilder {
+            size: 1,
+            admin: false,
+        }
+    }
+
+    fn connect(&self) -> Result<SciDBConnection, QueryError> {
+        SciDBConnection::new(
+            &self.creds.hostname,
+            &self.creds.username,
+            &self.creds.password,
+            self.creds.port,
+            self.creds.admin,
+        )
+    }
+
+    fn is_valid(conn: &mut SciDBConnection) -> bool {
+        conn.execute_query("list()").is_ok()
+    }
+
+    /// Check out a connection, validating (and if necessary replacing) a
+    /// recycled one before handing it to the caller. The returned guard
+    /// returns its connection to the pool when dropped.
+    pub fn get(&self) -> Result<PooledConnection<'_>, QueryError> {
+        loop {
+            // Pop a candidate and drop the lock before validating it: `is_valid`
+            // is a blocking round trip to SciDB, and holding the mutex across it
+            // would serialize every concurrent `get()` behind whichever caller
+            // is currently validating a connection.
+            let candidate = self.idle.lock().unwrap().pop();
+            match candidate {
+                Some(mut conn) => {
+                    if Self::is_valid(&mut conn) {
+                        return Ok(PooledConnection {
+                            conn: Some(conn),
+                            pool: self,
+                        });
+                    }
+                }
+                None => {
+                    return Ok(PooledConnection {
+                        conn: Some(self.connect()?),
+                        pool: self,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Record a query's `QueryStats` into this pool's aggregate latency
+    /// histogram, so a benchmarking harness can later read back p50/p99
+    /// percentiles across every query run against the pool.
+    pub fn record(&self, stats: &QueryStats) {
+        let micros = stats.total_duration().as_micros() as u64;
+        if let Err(e) = self.histogram.lock().unwrap().record(micros) {
+            eprintln!("SciDBPool: dropped a latency sample ({} us): {}", micros, e);
+        }
+    }
+
+    /// A snapshot of the aggregate latency histogram (in microseconds)
+    /// recorded via `record`.
+    pub fn histogram(&self) -> hdrhistogram::Histogram<u64> {
+        self.histogram.lock().unwrap().clone()
+    }
+}
+
+pub struct PooledConnection<'a> {
+    conn: Option<SciDBConnection>,
+    pool: &'a SciDBPool,
+}
+
+impl<'a> std::ops::Deref for PooledConnection<'a> {
+    type Target = SciDBConnection;
+    fn deref(&self) -> &SciDBConnection {
+        self.conn.as_ref().unwrap()
+    }
+}
+
+impl<'a> std::ops::DerefMut for PooledConnection<'a> {
+    fn deref_mut(&mut self) -> &mut SciDBConnection {
+        self.conn.as_mut().unwrap()
+    }
+}
+
+impl<'a> Drop for PooledConnection<'a> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.idle.lock().unwrap().push(conn);
+        }
+    }
+}
+
+//////////////////////////
+// AsyncSciDBConnection //
+//////////////////////////
+
+/* Every SciDBConnection method above blocks the calling thread on a
+ * synchronous FFI call into the C++ client. AsyncSciDBConnection gives
+ * the same operations an async surface by dispatching each call to
+ * tokio::task::spawn_blocking and awaiting the join handle, so a
+ * SciDBConnection can be driven from an async server without stalling the
+ * reactor. It takes ownership of a plain SciDBConnection; a connection
+ * checked out of a SciDBPool cannot be wrapped.
+ */
+
+fn join_error(e: tokio::task::JoinError) -> QueryError {
+    QueryError::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
+}
+
+pub struct AsyncSciDBConnection {
+    conn: Arc<Mutex<SciDBConnection>>,
+}
+
+impl AsyncSciDBConnection {
+    pub fn new(conn: SciDBConnection) -> AsyncSciDBConnection {
+        AsyncSciDBConnection {
+            conn: Arc::new(Mutex::new(conn)),
+        }
+    }
+
+    async fn run_blocking<F, T>(&self, f: F) -> Result<T, QueryError>
+    where
+        F: FnOnce(&mut SciDBConnection) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut conn = conn.lock().unwrap();
+            f(&mut conn)
+        })
+        .await
+        .map_err(join_error)
+    }
+
+    pub async fn prepare_query(
+        &self,
+        query: String,
+        result: Arc<Mutex<QueryResult>>,
+    ) -> Result<(), QueryError> {
+        match self
+            .run_blocking(move |conn| {
+                let result = result.lock().unwrap();
+                conn.prepare_query(&query, &result)
+            })
+            .await?
+        {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn execute_prepared_query(
+        &self,
+        query: String,
+        result: Arc<Mutex<QueryResult>>,
+    ) -> Result<(), QueryError> {
+        match self
+            .run_blocking(move |conn| {
+                let result = result.lock().unwrap();
+                conn.execute_prepared_query(&query, &result)
+            })
+            .await?
+        {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn complete_query(&self, result: Arc<Mutex<QueryResult>>) -> Result<(), QueryError> {
+        match self
+            .run_blocking(move |conn| {
+                let result = result.lock().unwrap();
+                conn.complete_query(&result)
+            })
+            .await?
+        {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn execute_query(&self, query: String) -> Result<QueryID, QueryError> {
+        self.run_blocking(move |conn| conn.execute_query(&query))
+            .await?
+    }
+
+    pub async fn execute_aio_query(&self, query: String) -> Result<AioQuery, QueryError> {
+        self.run_blocking(move |conn| conn.execute_aio_query(&query))
+            .await?
+    }
 }